@@ -1,5 +1,91 @@
+use arrow::array::RecordBatchReader;
+use arrow::ffi_stream::{ArrowArrayStreamReader, FFI_ArrowArrayStream};
+use extendr_api::libR_sys;
 use extendr_api::prelude::*;
 use polars::prelude::*;
+use std::sync::Arc;
+
+// an Arrow C stream exported by nanoarrow/arrow is a plain external pointer
+// tagged with this class; anything else falls back to the column-loop path
+const ARROW_STREAM_CLASS: &str = "nanoarrow_array_stream";
+
+// true when robj is an Arrow C-stream handle rather than a plain data.frame
+pub fn is_arrow_stream(robj: &Robj) -> bool {
+    robj.class()
+        .map(|mut classes| classes.any(|c| c == ARROW_STREAM_CLASS))
+        .unwrap_or(false)
+}
+
+// import an Arrow C stream directly into a polars dataframe, batch by batch,
+// without copying through R vectors
+pub fn from_arrow_stream(robj: &Robj) -> Result<DataFrame, Error> {
+    // the external pointer wraps the `ArrowArrayStream` struct that the
+    // R-side nanoarrow/arrow object owns and releases on drop. A stream
+    // genuinely produced by nanoarrow/arrow is a bare `R_MakeExternalPtr`
+    // around that struct and carries none of extendr's own type tag, so
+    // `ExternalPtr<T>`'s typed `TryFrom<Robj>` (which only recognizes
+    // externalptrs this crate itself created via `ExternalPtr::new`, as
+    // `to_arrow_stream` does) is tried first and falls back to the raw
+    // pointer address for a genuinely foreign xptr.
+    let ptr: *mut FFI_ArrowArrayStream = match ExternalPtr::<FFI_ArrowArrayStream>::try_from(
+        robj.clone(),
+    ) {
+        Ok(mut ext) => &mut *ext as *mut FFI_ArrowArrayStream,
+        Err(_) => {
+            if robj.rtype() != Rtype::ExternalPtr {
+                return Err(Error::from("robj is not a valid Arrow C-stream handle"));
+            }
+            unsafe { libR_sys::R_ExternalPtrAddr(robj.get()) as *mut FFI_ArrowArrayStream }
+        }
+    };
+    if ptr.is_null() {
+        return Err(Error::from("robj is not a valid Arrow C-stream handle"));
+    }
+
+    // `ptr::replace` leaves an empty/no-op stream behind in the R-owned
+    // externalptr, so its eventual GC finalizer calling `release()` again is
+    // a harmless no-op instead of a double-free of `private_data`
+    let stream = unsafe { std::ptr::replace(ptr, FFI_ArrowArrayStream::empty()) };
+
+    let reader = ArrowArrayStreamReader::try_new(stream)
+        .map_err(|e| Error::from(format!("Failed to open Arrow C stream: {}", e)))?;
+
+    let mut df: Option<DataFrame> = None;
+    for batch in reader {
+        let batch = batch.map_err(|e| Error::from(format!("Failed to read Arrow batch: {}", e)))?;
+        let chunk = DataFrame::try_from(batch).map_err(|e| Error::from(e.to_string()))?;
+        df = Some(match df {
+            Some(acc) => acc.vstack(&chunk).map_err(|e| Error::from(e.to_string()))?,
+            None => chunk,
+        });
+    }
+
+    df.ok_or(Error::from("Arrow C stream produced no batches"))
+}
+
+// export a polars dataframe as an Arrow C stream back to R, consumed by
+// nanoarrow::as_nanoarrow_array_stream()/arrow::as_record_batch_reader() on
+// the R side without per-element copies
+pub fn to_arrow_stream(mut df: DataFrame) -> Result<Robj, Error> {
+    // `vstack`-built frames (e.g. from `from_arrow_stream`) aren't rechunked,
+    // so every chunk must be forwarded, not just the first
+    let batches: Vec<_> = df
+        .align_chunks_par()
+        .iter_chunks(CompatLevel::newest(), false)
+        .map(|chunk| chunk.map(Into::into).map_err(|e| Error::from(e.to_string())))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let schema = df.schema().to_arrow(CompatLevel::newest());
+    let reader = arrow::record_batch::RecordBatchIterator::new(
+        batches.into_iter().map(Ok),
+        schema.into(),
+    );
+    let stream = FFI_ArrowArrayStream::new(Box::new(reader));
+
+    let mut robj: Robj = ExternalPtr::new(stream).into();
+    robj.set_class(&[ARROW_STREAM_CLASS]).unwrap();
+    Ok(robj)
+}
 
 // convert R dataframe to polars dataframe
 pub fn from_robj(robj: Robj) -> Result<DataFrame, Error> {
@@ -21,21 +107,109 @@ pub fn from_robj(robj: Robj) -> Result<DataFrame, Error> {
     // convert to polars series
     let mut series_vec = Vec::new();
     for (name, col) in names.zip(list.values()) {
-        let s = if col.is_real() {
-            let v = col
-                .as_real_vector()
-                .ok_or(Error::from(format!("Failed to convert {} to f64", name)))?;
-            Series::new(name.into(), v)
+        let s = if col.inherits("POSIXct") {
+            // R stores POSIXct as seconds (with fractional part) since the epoch
+            let v: Doubles = col
+                .try_into()
+                .map_err(|_| Error::from(format!("Failed to convert {} to POSIXct", name)))?;
+            let millis: Vec<Option<i64>> = v
+                .iter()
+                .map(|x| (!x.is_na()).then(|| (x.inner() * 1000.0).round() as i64))
+                .collect();
+            Series::new(name.into(), millis)
+                .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+                .map_err(|e| Error::from(e.to_string()))?
+        } else if col.inherits("Date") {
+            // R stores Date as whole days since the epoch
+            let v: Doubles = col
+                .try_into()
+                .map_err(|_| Error::from(format!("Failed to convert {} to Date", name)))?;
+            let days: Vec<Option<i32>> = v
+                .iter()
+                .map(|x| (!x.is_na()).then(|| x.inner() as i32))
+                .collect();
+            Series::new(name.into(), days)
+                .cast(&DataType::Date)
+                .map_err(|e| Error::from(e.to_string()))?
+        } else if col.is_factor() {
+            let levels: Vec<String> = col
+                .get_attrib("levels")
+                .and_then(|l| l.as_string_vector())
+                .ok_or(Error::from(format!("Failed to get levels for {}", name)))?;
+            let codes: Integers = col
+                .try_into()
+                .map_err(|_| Error::from(format!("Failed to convert {} to factor codes", name)))?;
+            let values: Vec<Option<&str>> = codes
+                .iter()
+                .map(|c| (!c.is_na()).then(|| levels[c.inner() as usize - 1].as_str()))
+                .collect();
+            let s = Series::new(name.into(), values);
+            // build the category set from the factor's declared `levels`, not
+            // from what's actually present in the data, so unused levels and
+            // the original level order both survive the round trip
+            let rev_map = Arc::new(RevMapping::build_local(Utf8ViewArray::from_iter_values(
+                levels.iter().map(|l| l.as_str()),
+            )));
+            let dtype = if col.inherits("ordered") {
+                DataType::Enum(Some(rev_map), CategoricalOrdering::Physical)
+            } else {
+                DataType::Categorical(Some(rev_map), CategoricalOrdering::Physical)
+            };
+            s.cast(&dtype).map_err(|e| Error::from(e.to_string()))?
+        } else if col.is_list() && !col.is_frame() {
+            // list-column: one nested atomic vector (or NULL) per row
+            let list = col
+                .as_list()
+                .ok_or(Error::from(format!("Failed to convert {} to list", name)))?;
+            let rows: Vec<Option<Series>> = list
+                .values()
+                .map(|elem| -> Result<Option<Series>, Error> {
+                    if elem.is_null() {
+                        Ok(None)
+                    } else {
+                        Ok(Some(element_to_series("item", elem)?))
+                    }
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+
+            // a heterogeneously-typed R list-column (e.g. list(1, "a", TRUE))
+            // has no single consistent inner dtype; reject it cleanly instead
+            // of letting `ListChunked` collection panic on mismatched types
+            if let Some(dtype) = rows.iter().flatten().map(|s| s.dtype()).next() {
+                if let Some(mismatch) = rows.iter().flatten().find(|s| s.dtype() != dtype) {
+                    return Err(Error::from(format!(
+                        "list-column {} mixes element types ({} and {}); only homogeneously-typed list-columns are supported",
+                        name, dtype, mismatch.dtype()
+                    )));
+                }
+            }
+
+            let ca: ListChunked = rows.into_iter().collect();
+            ca.with_name(name.into()).into_series()
+        } else if col.is_logical() {
+            let v: Logicals = col
+                .try_into()
+                .map_err(|_| Error::from(format!("Failed to convert {} to bool", name)))?;
+            let bools: Vec<Option<bool>> = v.iter().map(|b| b.as_bool()).collect();
+            Series::new(name.into(), bools)
+        } else if col.is_real() {
+            let v: Doubles = col
+                .try_into()
+                .map_err(|_| Error::from(format!("Failed to convert {} to f64", name)))?;
+            let vals: Vec<Option<f64>> = v.iter().map(|x| (!x.is_na()).then(|| x.inner())).collect();
+            Series::new(name.into(), vals)
         } else if col.is_integer() {
-            let v = col
-                .as_integer_vector()
-                .ok_or(Error::from(format!("Failed to convert {} to i32", name)))?;
-            Series::new(name.into(), v)
-        } else if col.is_string() || col.is_factor() {
-            let v = col
-                .as_string_vector()
-                .ok_or(Error::from(format!("Failed to convert {} to string", name)))?;
-            Series::new(name.into(), v)
+            let v: Integers = col
+                .try_into()
+                .map_err(|_| Error::from(format!("Failed to convert {} to i32", name)))?;
+            let vals: Vec<Option<i32>> = v.iter().map(|x| (!x.is_na()).then(|| x.inner())).collect();
+            Series::new(name.into(), vals)
+        } else if col.is_string() {
+            let v: Strings = col
+                .try_into()
+                .map_err(|_| Error::from(format!("Failed to convert {} to string", name)))?;
+            let vals: Vec<Option<&str>> = v.iter().map(|x| (!x.is_na()).then(|| x.as_str())).collect();
+            Series::new(name.into(), vals)
         } else {
             return Err(Error::from(format!("Unsupported column type: {}", name)));
         };
@@ -48,18 +222,128 @@ pub fn from_robj(robj: Robj) -> Result<DataFrame, Error> {
     Ok(df)
 }
 
-pub fn to_robj(df: DataFrame) -> Robj {
+// convert a single (atomic, non-nested) R vector to a polars Series, used to
+// build the rows of a list-column
+fn element_to_series(name: &str, elem: Robj) -> Result<Series, Error> {
+    if elem.is_real() {
+        let v: Doubles = elem
+            .try_into()
+            .map_err(|_| Error::from("Failed to convert list-column element to f64"))?;
+        let vals: Vec<Option<f64>> = v.iter().map(|x| (!x.is_na()).then(|| x.inner())).collect();
+        Ok(Series::new(name.into(), vals))
+    } else if elem.is_integer() {
+        let v: Integers = elem
+            .try_into()
+            .map_err(|_| Error::from("Failed to convert list-column element to i32"))?;
+        let vals: Vec<Option<i32>> = v.iter().map(|x| (!x.is_na()).then(|| x.inner())).collect();
+        Ok(Series::new(name.into(), vals))
+    } else if elem.is_logical() {
+        let v: Logicals = elem
+            .try_into()
+            .map_err(|_| Error::from("Failed to convert list-column element to bool"))?;
+        let bools: Vec<Option<bool>> = v.iter().map(|b| b.as_bool()).collect();
+        Ok(Series::new(name.into(), bools))
+    } else if elem.is_string() {
+        let v: Strings = elem
+            .try_into()
+            .map_err(|_| Error::from("Failed to convert list-column element to string"))?;
+        let vals: Vec<Option<&str>> = v.iter().map(|x| (!x.is_na()).then(|| x.as_str())).collect();
+        Ok(Series::new(name.into(), vals))
+    } else {
+        Err(Error::from("Unsupported list-column element type"))
+    }
+}
+
+pub fn to_robj(df: DataFrame) -> Result<Robj, Error> {
     let mut r_cols = Vec::new();
     for s in df.get_columns() {
         let name = s.name().to_string();
         let robj = if s.dtype().is_float() {
-            Robj::from(s.f64().unwrap().into_iter().map(|v| v.unwrap_or(f64::NAN)).collect::<Vec<_>>())
+            // `from_parquet`/`from_ipc`/`from_arrow_stream` can hand back a
+            // Float32 (or other float width) column that never occurs on the
+            // `from_robj` path; widen to the Float64 R itself uses before
+            // downcasting, instead of assuming the series is already f64
+            let s = s
+                .cast(&DataType::Float64)
+                .map_err(|e| Error::from(e.to_string()))?;
+            Doubles::from_values(s.f64().unwrap().into_iter().map(|v| match v {
+                Some(v) => Rfloat::from(v),
+                None => Rfloat::na(),
+            }))
+            .into()
         } else if s.dtype().is_integer() {
-            Robj::from(s.i32().unwrap().into_iter().map(|v| v.unwrap_or(i32::MIN)).collect::<Vec<_>>())
+            // same widening for Int8/16/64/UInt* columns from file-backed
+            // sources; R has no native type wider than i32, so this is
+            // lossy for values outside i32 range, same as from_robj's limit
+            let s = s
+                .cast(&DataType::Int32)
+                .map_err(|e| Error::from(e.to_string()))?;
+            Integers::from_values(s.i32().unwrap().into_iter().map(|v| match v {
+                Some(v) => Rint::from(v),
+                None => Rint::na(),
+            }))
+            .into()
+        } else if s.dtype() == &DataType::Boolean {
+            Logicals::from_values(s.bool().unwrap().into_iter().map(|v| match v {
+                Some(v) => Rbool::from(v),
+                None => Rbool::na(),
+            }))
+            .into()
+        } else if matches!(s.dtype(), DataType::Categorical(..) | DataType::Enum(..)) {
+            let cats = s.categorical().unwrap();
+            let levels: Vec<String> = cats
+                .get_rev_map()
+                .get_categories()
+                .into_iter()
+                .map(|v| v.unwrap_or_default().to_string())
+                .collect();
+            let codes: Vec<Option<i32>> = cats
+                .physical()
+                .into_iter()
+                .map(|c| c.map(|c| c as i32 + 1))
+                .collect();
+            let ordered = matches!(s.dtype(), DataType::Enum(..));
+            factor_robj(&codes, &levels, ordered)
         } else if s.dtype().is_string() {
-            Robj::from(s.str().unwrap().into_iter().map(|v| v.unwrap_or("").to_string()).collect::<Vec<_>>())
+            Strings::from_values(s.str().unwrap().into_iter().map(|v| match v {
+                Some(v) => Rstr::from(v),
+                None => Rstr::na(),
+            }))
+            .into()
         } else if s.dtype().is_date() {
-            Robj::from(s.date().unwrap().into_iter().map(|v| v.unwrap_or(0)).collect::<Vec<_>>())
+            // polars Date is days since the epoch, same representation R uses
+            let days = Doubles::from_values(s.date().unwrap().into_iter().map(|v| match v {
+                Some(v) => Rfloat::from(v as f64),
+                None => Rfloat::na(),
+            }));
+            let mut robj: Robj = days.into();
+            robj.set_class(&["Date"]).unwrap();
+            robj
+        } else if matches!(s.dtype(), DataType::Datetime(..)) {
+            // R expects POSIXct as seconds since the epoch
+            let unit_divisor = match s.dtype() {
+                DataType::Datetime(TimeUnit::Milliseconds, _) => 1_000.0,
+                DataType::Datetime(TimeUnit::Microseconds, _) => 1_000_000.0,
+                DataType::Datetime(TimeUnit::Nanoseconds, _) => 1_000_000_000.0,
+                _ => 1.0,
+            };
+            let secs = Doubles::from_values(s.datetime().unwrap().into_iter().map(|v| match v {
+                Some(v) => Rfloat::from(v as f64 / unit_divisor),
+                None => Rfloat::na(),
+            }));
+            let mut robj: Robj = secs.into();
+            robj.set_class(&["POSIXct", "POSIXt"]).unwrap();
+            robj
+        } else if matches!(s.dtype(), DataType::List(_)) {
+            let ca = s.list().unwrap();
+            let rows: Vec<Robj> = ca
+                .into_iter()
+                .map(|row| match row {
+                    Some(inner) => series_to_element_robj(&inner),
+                    None => Ok(Robj::from(())),
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            List::from_values(rows).into()
         } else {
             Robj::from(())
         };
@@ -71,6 +355,285 @@ pub fn to_robj(df: DataFrame) -> Robj {
 
     let mut result = List::from_values(values);
     result.set_names(&names).unwrap();
-    
-    data_frame!(result)
+
+    Ok(data_frame!(result))
+}
+
+// convert a single row of a polars list-column back to a plain R vector; the
+// counterpart of `element_to_series` on the way out
+fn series_to_element_robj(s: &Series) -> Result<Robj, Error> {
+    let robj = if s.dtype().is_float() {
+        // same Float32/etc. widening as to_robj, in case a list-column's
+        // inner elements ever carry a non-f64 float width
+        let s = s
+            .cast(&DataType::Float64)
+            .map_err(|e| Error::from(e.to_string()))?;
+        Doubles::from_values(s.f64().unwrap().into_iter().map(|v| match v {
+            Some(v) => Rfloat::from(v),
+            None => Rfloat::na(),
+        }))
+        .into()
+    } else if s.dtype().is_integer() {
+        let s = s
+            .cast(&DataType::Int32)
+            .map_err(|e| Error::from(e.to_string()))?;
+        Integers::from_values(s.i32().unwrap().into_iter().map(|v| match v {
+            Some(v) => Rint::from(v),
+            None => Rint::na(),
+        }))
+        .into()
+    } else if s.dtype() == &DataType::Boolean {
+        Logicals::from_values(s.bool().unwrap().into_iter().map(|v| match v {
+            Some(v) => Rbool::from(v),
+            None => Rbool::na(),
+        }))
+        .into()
+    } else if s.dtype().is_string() {
+        Strings::from_values(s.str().unwrap().into_iter().map(|v| match v {
+            Some(v) => Rstr::from(v),
+            None => Rstr::na(),
+        }))
+        .into()
+    } else {
+        Robj::from(())
+    };
+    Ok(robj)
+}
+
+// build an R factor (ordered or not) from 1-based codes and their level labels
+fn factor_robj(codes: &[Option<i32>], levels: &[String], ordered: bool) -> Robj {
+    let codes = Integers::from_values(codes.iter().map(|c| match c {
+        Some(c) => Rint::from(*c),
+        None => Rint::na(),
+    }));
+    let mut robj: Robj = codes.into();
+    robj.set_attrib("levels", levels).unwrap();
+    if ordered {
+        robj.set_class(&["ordered", "factor"]).unwrap();
+    } else {
+        robj.set_class(&["factor"]).unwrap();
+    }
+    robj
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // exercises the Arrow C-stream bridge end to end: export a polars
+    // dataframe to an R-facing stream handle, then re-import it, without
+    // going through nanoarrow/arrow on the R side
+    #[test]
+    fn arrow_stream_round_trip_preserves_rows_and_class_tag() {
+        test! {
+            let df = DataFrame::new(vec![
+                Series::new("id".into(), &[1i32, 2, 3]).into(),
+                Series::new("value".into(), &[1.5f64, 2.5, 3.5]).into(),
+            ])?;
+
+            let exported = to_arrow_stream(df.clone())?;
+            assert!(
+                is_arrow_stream(&exported),
+                "exported stream must carry the nanoarrow_array_stream class"
+            );
+
+            let imported = from_arrow_stream(&exported)?;
+            assert_eq!(imported.shape(), df.shape());
+            assert_eq!(
+                imported.column("id")?.i32()?.into_no_null_iter().collect::<Vec<_>>(),
+                vec![1, 2, 3]
+            );
+        }
+    }
+
+    // a stream built straight from the `arrow` crate's own record-batch
+    // types, wrapped in an R external pointer via the bare `R_MakeExternalPtr`
+    // C call rather than this crate's own `ExternalPtr::new(...).into()` --
+    // the construction nanoarrow/arrow's `as_nanoarrow_array_stream()`
+    // actually use, which carries none of extendr's own type tag. A prior
+    // version of this test still went through `ExternalPtr::new`, so it
+    // never actually proved `from_arrow_stream` can ingest a genuinely
+    // foreign xptr, only one this crate tagged itself. It also carries
+    // Int64/Float32 columns that never occur on the from_robj path, which
+    // `to_robj` must widen instead of panicking on the i32()/f64() downcast.
+    #[test]
+    fn arrow_stream_from_external_producer_preserves_wide_numeric_types() {
+        use arrow::array::{Float32Array, Int64Array};
+        use arrow::datatypes::{DataType as ArrowDataType, Field, Schema};
+        use arrow::record_batch::{RecordBatch, RecordBatchIterator};
+
+        test! {
+            let schema = Arc::new(Schema::new(vec![
+                Field::new("id", ArrowDataType::Int64, false),
+                Field::new("value", ArrowDataType::Float32, false),
+            ]));
+            let batch = RecordBatch::try_new(
+                schema.clone(),
+                vec![
+                    Arc::new(Int64Array::from(vec![1i64, 2, 3])),
+                    Arc::new(Float32Array::from(vec![1.5f32, 2.5, 3.5])),
+                ],
+            )
+            .map_err(|e| Error::from(e.to_string()))?;
+
+            let reader = RecordBatchIterator::new(vec![Ok(batch)].into_iter(), schema);
+            let stream = FFI_ArrowArrayStream::new(Box::new(reader));
+
+            // hand-roll the externalptr the way nanoarrow/arrow do: a bare
+            // `R_MakeExternalPtr` around the raw struct, with no tag and
+            // none of `ExternalPtr::new`'s own bookkeeping
+            let raw = Box::into_raw(Box::new(stream)) as *mut std::ffi::c_void;
+            let mut robj = unsafe {
+                Robj::from_sexp(libR_sys::R_MakeExternalPtr(
+                    raw,
+                    libR_sys::R_NilValue,
+                    libR_sys::R_NilValue,
+                ))
+            };
+            robj.set_class(&[ARROW_STREAM_CLASS]).unwrap();
+
+            let imported = from_arrow_stream(&robj)?;
+            assert_eq!(imported.dtypes(), vec![DataType::Int64, DataType::Float32]);
+
+            // must not panic downcasting Int64/Float32 to R's i32/f64
+            let out = to_robj(imported)?;
+            let matches: bool = R!(r#"
+                identical({{out}}$id, c(1L, 2L, 3L)) &&
+                    identical({{out}}$value, c(1.5, 2.5, 3.5))
+            "#)?
+            .as_bool()
+            .unwrap();
+            assert!(matches);
+        }
+    }
+
+    // round-trips a logical column with an NA through from_robj/to_robj
+    #[test]
+    fn logical_round_trip_preserves_na_and_values() {
+        test! {
+            let df_in: Robj = R!(r#"data.frame(flag = c(TRUE, FALSE, NA))"#)?;
+            let df = from_robj(df_in)?;
+            let out = to_robj(df)?;
+
+            let matches: bool =
+                R!(r#"identical({{out}}$flag, c(TRUE, FALSE, NA))"#)?.as_bool().unwrap();
+            assert!(matches);
+        }
+    }
+
+    // round-trips a Date column with an NA; R stores Date as whole days since
+    // the epoch, matching polars' `Date` dtype, so this catches any stray
+    // unit conversion
+    #[test]
+    fn date_round_trip_preserves_days_and_na() {
+        test! {
+            let df_in: Robj =
+                R!(r#"data.frame(d = as.Date(c("2024-01-01", NA, "2024-03-01")))"#)?;
+            let df = from_robj(df_in)?;
+            let out = to_robj(df)?;
+
+            let matches: bool = R!(r#"
+                expected <- as.Date(c("2024-01-01", NA, "2024-03-01"))
+                inherits({{out}}$d, "Date") && identical(as.numeric({{out}}$d), as.numeric(expected))
+            "#)?.as_bool().unwrap();
+            assert!(matches);
+        }
+    }
+
+    // round-trips a POSIXct column with an NA; covers the seconds-to-ms and
+    // back ms-to-seconds rounding on each side of the bridge
+    #[test]
+    fn posixct_round_trip_preserves_values_and_na() {
+        test! {
+            let df_in: Robj = R!(r#"
+                data.frame(ts = as.POSIXct(
+                    c("2024-01-01 00:00:00", NA, "2024-01-01 12:30:45"),
+                    tz = "UTC"
+                ))
+            "#)?;
+            let df = from_robj(df_in)?;
+            let out = to_robj(df)?;
+
+            let matches: bool = R!(r#"
+                expected <- as.POSIXct(
+                    c("2024-01-01 00:00:00", NA, "2024-01-01 12:30:45"),
+                    tz = "UTC"
+                )
+                inherits({{out}}$ts, "POSIXct") &&
+                    isTRUE(all.equal(as.numeric({{out}}$ts), as.numeric(expected)))
+            "#)?.as_bool().unwrap();
+            assert!(matches);
+        }
+    }
+
+    // round-trips an unordered factor; the declared `levels` (not just the
+    // values actually present) must survive, including unused levels and
+    // their original order
+    #[test]
+    fn factor_round_trip_preserves_levels_order_and_na() {
+        test! {
+            let df_in: Robj = R!(r#"
+                data.frame(grade = factor(c("b", NA, "a"), levels = c("a", "b", "c")))
+            "#)?;
+            let df = from_robj(df_in)?;
+            let out = to_robj(df)?;
+
+            let matches: bool = R!(r#"
+                expected <- factor(c("b", NA, "a"), levels = c("a", "b", "c"))
+                identical(levels({{out}}$grade), levels(expected)) &&
+                    identical(as.character({{out}}$grade), as.character(expected))
+            "#)?.as_bool().unwrap();
+            assert!(matches);
+        }
+    }
+
+    // round-trips an ordered factor, verifying the `ordered`/`factor` class
+    // (not just `factor`) survives alongside levels and values
+    #[test]
+    fn ordered_factor_round_trip_preserves_order_class() {
+        test! {
+            let df_in: Robj = R!(r#"
+                data.frame(tier = factor(c("lo", "hi"), levels = c("lo", "mid", "hi"), ordered = TRUE))
+            "#)?;
+            let df = from_robj(df_in)?;
+            let out = to_robj(df)?;
+
+            let matches: bool = R!(r#"
+                expected <- factor(c("lo", "hi"), levels = c("lo", "mid", "hi"), ordered = TRUE)
+                is.ordered({{out}}$tier) &&
+                    identical(levels({{out}}$tier), levels(expected)) &&
+                    identical(as.character({{out}}$tier), as.character(expected))
+            "#)?.as_bool().unwrap();
+            assert!(matches);
+        }
+    }
+
+    // a homogeneously-typed list-column (the supported/documented case) must
+    // round-trip its per-row vectors, including a NULL row
+    #[test]
+    fn homogeneous_list_column_round_trips() {
+        test! {
+            let df_in: Robj = R!(r#"data.frame(nums = I(list(c(1, 2), 3, NULL)))"#)?;
+            let df = from_robj(df_in)?;
+            let out = to_robj(df)?;
+
+            let matches: bool = R!(r#"
+                identical({{out}}$nums[[1]], c(1, 2)) &&
+                    identical({{out}}$nums[[2]], 3) &&
+                    is.null({{out}}$nums[[3]])
+            "#)?.as_bool().unwrap();
+            assert!(matches);
+        }
+    }
+
+    // a list-column whose rows don't share a common element type has no
+    // single consistent inner dtype; from_robj must return a clean `Err`
+    // instead of panicking while building the `ListChunked`
+    #[test]
+    fn heterogeneous_list_column_returns_error_instead_of_panicking() {
+        test! {
+            let df_in: Robj = R!(r#"data.frame(mixed = I(list(1, "a", TRUE)))"#)?;
+            assert!(from_robj(df_in).is_err());
+        }
+    }
 }
\ No newline at end of file
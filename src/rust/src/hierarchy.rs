@@ -15,9 +15,94 @@
 // You should have received a copy of the GNU General Public License
 // along with rhts.  If not, see <https://www.gnu.org/licenses/\>.
 
-use crate::helpers::{from_robj, to_robj};
+use crate::helpers::{from_arrow_stream, from_robj, is_arrow_stream, to_arrow_stream, to_robj};
 use extendr_api::prelude::*;
-use hts_core::{HierarchicalTimeSeries, HierarchySpec};
+use hts_core::{AggFn as HtsAggFn, HierarchicalTimeSeries, HierarchySpec};
+
+/// Aggregation function used to roll child series up to their parent
+/// @description
+/// Selects how a parent node's value is derived from its children during
+/// reconciliation. Defaults to `sum()`, which is correct for additive
+/// quantities like counts or revenue; use the other constructors for rates,
+/// indices, or prices that do not sum meaningfully across children.
+/// @usage NULL
+/// @format NULL
+/// @export
+#[extendr]
+pub struct AggFn {
+    inner: HtsAggFn,
+}
+
+#[extendr]
+impl AggFn {
+    /// Sum of children (default, correct for additive quantities)
+    /// @return An AggFn object
+    /// @examples
+    /// AggFn$sum()
+    pub fn sum() -> Self {
+        Self { inner: HtsAggFn::Sum }
+    }
+
+    /// Arithmetic mean of children
+    /// @return An AggFn object
+    /// @examples
+    /// AggFn$mean()
+    pub fn mean() -> Self {
+        Self { inner: HtsAggFn::Mean }
+    }
+
+    /// Median (50th percentile) of children, with continuous interpolation
+    /// @return An AggFn object
+    /// @examples
+    /// AggFn$median()
+    pub fn median() -> Self {
+        Self {
+            inner: HtsAggFn::Percentile(0.5),
+        }
+    }
+
+    /// p-th percentile of children, with continuous interpolation
+    /// @description
+    /// Sorts the child values, computes `rank = p * (n - 1)`, and linearly
+    /// interpolates between `floor(rank)` and `ceil(rank)`.
+    /// @param p (`double`)\cr Percentile in `[0, 1]`
+    /// @return An AggFn object
+    /// @examples
+    /// AggFn$percentile(0.9)
+    pub fn percentile(p: f64) -> Self {
+        Self {
+            inner: HtsAggFn::Percentile(p),
+        }
+    }
+
+    /// p-th percentile of children, discrete (nearest-rank) variant
+    /// @description
+    /// Sorts the child values and returns the smallest value whose cumulative
+    /// count fraction is `>= p`, rather than interpolating between two values
+    /// like `percentile()` does.
+    /// @param p (`double`)\cr Percentile in `[0, 1]`
+    /// @return An AggFn object
+    /// @examples
+    /// AggFn$percentile_discrete(0.9)
+    pub fn percentile_discrete(p: f64) -> Self {
+        Self {
+            inner: HtsAggFn::PercentileDiscrete(p),
+        }
+    }
+
+    /// Most frequent value among children (ties broken by the lowest value)
+    /// @return An AggFn object
+    /// @examples
+    /// AggFn$mode()
+    pub fn mode() -> Self {
+        Self { inner: HtsAggFn::Mode }
+    }
+
+    /// Print method for AggFn
+    pub fn print(&self) {
+        rprintln!("<AggFn> {:?}", self.inner);
+    }
+}
 
 /// Specification of hierarchical and grouped structure.
 /// @description
@@ -41,6 +126,8 @@ impl HtsSpec {
     /// Instantiate a new HierarchySpec
     /// @param hierarchy Character vector of hierarchical column names (ordered from top to bottom)
     /// @param groups Character vector of grouped column names
+    /// @param agg_fn (`AggFn`)\cr
+    /// How children roll up to their parent. Defaults to `AggFn$sum()`.
     /// @return A new HierarchySpec object
     /// @examples
     /// # Hierarchical only
@@ -51,10 +138,23 @@ impl HtsSpec {
     ///   hierarchy = c("State", "Region"),
     ///   groups = c("Purpose")
     /// )
-    pub fn new(hierarchy: Vec<String>, groups: Vec<String>) -> Self {
-        Self {
-            inner: HierarchySpec::new(hierarchy, groups),
+    ///
+    /// # Mean rollup, for rate/index series
+    /// spec <- HtsSpec$new(
+    ///   hierarchy = c("State", "Region"),
+    ///   groups = c(),
+    ///   agg_fn = AggFn$mean()
+    /// )
+    pub fn new(
+        hierarchy: Vec<String>,
+        groups: Vec<String>,
+        #[default = "NULL"] agg_fn: Nullable<&AggFn>,
+    ) -> Self {
+        let mut inner = HierarchySpec::new(hierarchy, groups);
+        if let Nullable::NotNull(agg_fn) = agg_fn {
+            inner = inner.with_agg_fn(agg_fn.inner.clone());
         }
+        Self { inner }
     }
 
     /// Create a spec with only hierarchical columns (no grouping)
@@ -79,11 +179,23 @@ impl HtsSpec {
         }
     }
 
+    /// Set the aggregation function used to roll children up to their parent
+    /// @param agg_fn (`AggFn`)\cr How children roll up
+    /// @return A new HierarchySpec object with the aggregation function set
+    /// @examples
+    /// spec <- HtsSpec$hierarchical(c("State", "Region"))$with_agg_fn(AggFn$median())
+    pub fn with_agg_fn(&self, agg_fn: &AggFn) -> Self {
+        Self {
+            inner: self.inner.clone().with_agg_fn(agg_fn.inner.clone()),
+        }
+    }
+
     /// Print method for HierarchySpec
     pub fn print(&self) {
         rprintln!("<HierarchySpec>");
         rprintln!("  Hierarchy: {:?}", self.inner.hierarchy);
         rprintln!("  Groups: {:?}", self.inner.groups);
+        rprintln!("  Aggregation: {:?}", self.inner.agg_fn);
     }
 }
 
@@ -106,6 +218,10 @@ impl Hts {
     /// @param spec HierarchySpec object defining the structure
     /// @param time_col Name of the time/period column
     /// @param value_col Name of the value column
+    /// @param lazy (`logical`)\cr
+    /// If `TRUE`, build the aggregation path as a polars `LazyFrame` and only
+    /// `collect()` it when results are requested, instead of eagerly
+    /// materializing every aggregation level up front. Default `FALSE`.
     /// @return A new HierarchicalTimeSeries object
     /// @examples
     /// hts_data <- data.frame(
@@ -143,12 +259,18 @@ impl Hts {
         spec: &HtsSpec,
         time_col: &str,
         value_col: &str,
+        #[default = "FALSE"] lazy: bool,
     ) -> Result<Self, Error> {
-        // create polars dataframe
-        let df = from_robj(bottom_level)?;
+        // zero-copy import when R hands us an Arrow C stream; otherwise fall
+        // back to the per-element column loop for plain data.frames
+        let df = if is_arrow_stream(&bottom_level) {
+            from_arrow_stream(&bottom_level)?
+        } else {
+            from_robj(bottom_level)?
+        };
 
         // create Hts
-        let inner = HierarchicalTimeSeries::new(df, spec.inner.clone(), time_col, value_col)
+        let inner = HierarchicalTimeSeries::new(df, spec.inner.clone(), time_col, value_col, lazy)
             .map_err(|e| Error::from(e.to_string()))?;
 
         Ok(Self { inner })
@@ -159,6 +281,9 @@ impl Hts {
     /// @param spec HierarchySpec object defining the structure
     /// @param time_col Name of the time/period column
     /// @param value_col Name of the value column
+    /// @param lazy (`logical`)\cr
+    /// If `TRUE`, `scan_csv` the file into a `LazyFrame` instead of reading it
+    /// eagerly, deferring aggregation until results are collected. Default `FALSE`.
     /// @return A new HierarchicalTimeSeries object
     /// @examples
     /// \dontrun{
@@ -171,9 +296,78 @@ impl Hts {
         spec: &HtsSpec,
         time_col: &str,
         value_col: &str,
+        #[default = "FALSE"] lazy: bool,
     ) -> Result<Self, Error> {
-        let inner = HierarchicalTimeSeries::from_csv(path, spec.inner.clone(), time_col, value_col)
-            .map_err(|e| format!("Failed to load CSV: {}", e))?;
+        let inner =
+            HierarchicalTimeSeries::from_csv(path, spec.inner.clone(), time_col, value_col, lazy)
+                .map_err(|e| format!("Failed to load CSV: {}", e))?;
+        Ok(Self { inner })
+    }
+
+    /// Load from Parquet file
+    /// @description
+    /// Backed by polars `scan_parquet`, so datetime, categorical, and boolean
+    /// columns are preserved natively instead of being sniffed as in
+    /// `from_csv`/`Hts$new`.
+    /// @param path Path to Parquet file
+    /// @param spec HierarchySpec object defining the structure
+    /// @param time_col Name of the time/period column
+    /// @param value_col Name of the value column
+    /// @param lazy (`logical`)\cr
+    /// If `TRUE`, keep the scan as a `LazyFrame` instead of collecting it
+    /// eagerly, deferring aggregation until results are collected. Default `FALSE`.
+    /// @return A new HierarchicalTimeSeries object
+    /// @examples
+    /// \dontrun{
+    /// spec <- HtsSpec$new(c("State", "Region"), c("Purpose"))
+    /// hts <- Hts$from_parquet("data.parquet", spec, "Quarter", "Trips")
+    /// }
+    pub fn from_parquet(
+        path: &str,
+        spec: &HtsSpec,
+        time_col: &str,
+        value_col: &str,
+        #[default = "FALSE"] lazy: bool,
+    ) -> Result<Self, Error> {
+        let inner = HierarchicalTimeSeries::from_parquet(
+            path,
+            spec.inner.clone(),
+            time_col,
+            value_col,
+            lazy,
+        )
+        .map_err(|e| format!("Failed to load Parquet: {}", e))?;
+        Ok(Self { inner })
+    }
+
+    /// Load from Arrow IPC (Feather) file
+    /// @description
+    /// Backed by polars `scan_ipc`, so datetime, categorical, and boolean
+    /// columns are preserved natively instead of being sniffed as in
+    /// `from_csv`/`Hts$new`.
+    /// @param path Path to Arrow IPC file
+    /// @param spec HierarchySpec object defining the structure
+    /// @param time_col Name of the time/period column
+    /// @param value_col Name of the value column
+    /// @param lazy (`logical`)\cr
+    /// If `TRUE`, keep the scan as a `LazyFrame` instead of collecting it
+    /// eagerly, deferring aggregation until results are collected. Default `FALSE`.
+    /// @return A new HierarchicalTimeSeries object
+    /// @examples
+    /// \dontrun{
+    /// spec <- HtsSpec$new(c("State", "Region"), c("Purpose"))
+    /// hts <- Hts$from_ipc("data.arrow", spec, "Quarter", "Trips")
+    /// }
+    pub fn from_ipc(
+        path: &str,
+        spec: &HtsSpec,
+        time_col: &str,
+        value_col: &str,
+        #[default = "FALSE"] lazy: bool,
+    ) -> Result<Self, Error> {
+        let inner =
+            HierarchicalTimeSeries::from_ipc(path, spec.inner.clone(), time_col, value_col, lazy)
+                .map_err(|e| format!("Failed to load Arrow IPC: {}", e))?;
         Ok(Self { inner })
     }
 
@@ -221,18 +415,635 @@ impl Hts {
     }
 
     /// Get aggregated series
-    /// @return Dataframe containing all series with their hierarchical labels
-    pub fn aggregated_series(&self) -> Result<Robj, Error> {
+    /// @param as_arrow_stream (`logical`)\cr
+    /// If `TRUE`, return the result as an Arrow C stream (consumable with
+    /// `nanoarrow::as_nanoarrow_array_stream()`) instead of a `data.frame`,
+    /// avoiding a per-element copy on the way out. Default `FALSE`.
+    /// @return Dataframe (or Arrow C stream) containing all series with their
+    /// hierarchical labels
+    pub fn aggregated_series(&self, #[default = "FALSE"] as_arrow_stream: bool) -> Result<Robj, Error> {
         let polars_df = self.inner.aggregate_all()
             .map_err(|e| Error::from(e.to_string()))?;
 
+        if as_arrow_stream {
+            to_arrow_stream(polars_df)
+        } else {
+            // convert polars dataframe to R dataframe
+            Ok(to_robj(polars_df)?.into())
+        }
+    }
+
+    /// Get aggregated series via a streaming collect
+    /// @description
+    /// Equivalent to `aggregated_series()`, but collects the underlying
+    /// `LazyFrame` using polars' streaming engine so hierarchies whose full
+    /// cross-product does not fit in memory can still be reconciled. Only
+    /// meaningful when the object was built with `lazy = TRUE`.
+    /// @return Dataframe containing all series with their hierarchical labels
+    pub fn aggregated_series_streaming(&self) -> Result<Robj, Error> {
+        let polars_df = self
+            .inner
+            .aggregate_all_streaming()
+            .map_err(|e| Error::from(e.to_string()))?;
+
         // convert polars dataframe to R dataframe
-        Ok(to_robj(polars_df).into())
+        Ok(to_robj(polars_df)?.into())
+    }
+
+    /// Get the direct children of a node
+    /// @param node_label (`character`)\cr Label of the node to query
+    /// @return Character vector of the node's direct children's labels
+    pub fn children(&self, node_label: &str) -> Result<Vec<String>, Error> {
+        self.inner
+            .children(node_label)
+            .map_err(|e| Error::from(e.to_string()))
+    }
+
+    /// Get the parent of a node
+    /// @param node_label (`character`)\cr Label of the node to query
+    /// @return The parent's label, or `NULL` if `node_label` is the root
+    pub fn parent(&self, node_label: &str) -> Result<Nullable<String>, Error> {
+        self.inner
+            .parent(node_label)
+            .map(|p| p.map_or(Nullable::Null, Nullable::NotNull))
+            .map_err(|e| Error::from(e.to_string()))
+    }
+
+    /// Get all leaf (bottom-level) descendants of a node
+    /// @param node_label (`character`)\cr Label of the node to query
+    /// @return Character vector of the labels of every leaf under `node_label`
+    pub fn leaves_under(&self, node_label: &str) -> Result<Vec<String>, Error> {
+        self.inner
+            .leaves_under(node_label)
+            .map_err(|e| Error::from(e.to_string()))
+    }
+
+    /// Restrict the hierarchy to a node's descendants
+    /// @description
+    /// Returns a new `Hts` built only from the bottom-level series that fall
+    /// under `node_label`, with its own hierarchy tree and summation matrix.
+    /// @param node_label (`character`)\cr Label of the node to restrict to
+    /// @return A new Hts object containing only `node_label`'s subtree
+    pub fn subtree(&self, node_label: &str) -> Result<Self, Error> {
+        let inner = self
+            .inner
+            .subtree(node_label)
+            .map_err(|e| Error::from(e.to_string()))?;
+        Ok(Self { inner })
+    }
+
+    /// Export the hierarchy tree as a nested `dendrogram` object
+    /// @description
+    /// Builds a nested list carrying the `dendrogram` class and the
+    /// `members`/`height`/`midpoint`/`leaf` attributes base R expects, so the
+    /// result can be plotted and traversed with `plot()`, `str()`, `labels()`
+    /// and friends without any conversion on the R side. Unlike `hclust`'s
+    /// `merge` matrix, this representation is not restricted to binary
+    /// merges, so nodes with more than two children are preserved as-is
+    /// instead of being forced into a sequence of pairwise cherries.
+    /// @return A nested list of class `dendrogram`, rooted at the top of the
+    /// hierarchy
+    pub fn as_dendrogram(&self) -> Result<Robj, Error> {
+        let root_label = root_label(&self.inner)?;
+        let mut next_leaf_index = 1;
+        let (robj, ..) = build_dendrogram_node(&self.inner, &root_label, &mut next_leaf_index)?;
+        Ok(robj)
     }
 }
 
+// the one node with no parent in the computed tree
+fn root_label(hts: &HierarchicalTimeSeries) -> Result<String, Error> {
+    let dendrogram = hts.dendrogram();
+    dendrogram
+        .labels
+        .into_iter()
+        .zip(dendrogram.parents)
+        .find(|(_, parent)| parent.is_none())
+        .map(|(label, _)| label)
+        .ok_or_else(|| Error::from("hierarchy tree has no root"))
+}
+
+// recursively builds a base R `dendrogram` node, returning the node itself
+// alongside the (members, height, midpoint) its parent needs to lay out its
+// own children; `midpoint` follows base R's convention of centering a node
+// over the horizontal span between its first and last child's anchor point
+fn build_dendrogram_node(
+    hts: &HierarchicalTimeSeries,
+    label: &str,
+    next_leaf_index: &mut i32,
+) -> Result<(Robj, i32, f64, f64), Error> {
+    let children = hts
+        .children(label)
+        .map_err(|e| Error::from(e.to_string()))?;
+
+    if children.is_empty() {
+        // base R's dendrogram leaves carry their left-to-right position
+        // (1..n) as the node's own value, not just as a `label` attribute;
+        // `order.dendrogram()` and similar tooling read that value directly
+        let index = *next_leaf_index;
+        *next_leaf_index += 1;
+        let mut robj: Robj = Rint::from(index).into();
+        robj.set_attrib("label", label)?;
+        robj.set_attrib("members", 1)?;
+        robj.set_attrib("height", 0.0)?;
+        robj.set_attrib("leaf", true)?;
+        // a single-node root (no internal levels above the bottom) returns
+        // straight out of this branch via as_dendrogram(), so it must carry
+        // the `dendrogram` class itself, not just inherit it from the parent
+        // list node built below
+        robj.set_class(&["dendrogram"])?;
+        return Ok((robj, 1, 0.0, 0.0));
+    }
+
+    let mut nodes = Vec::with_capacity(children.len());
+    let mut offset = 0.0;
+    let mut first_pos = 0.0;
+    let mut last_pos = 0.0;
+    let mut members = 0;
+    let mut height: f64 = 0.0;
+
+    for (i, child_label) in children.iter().enumerate() {
+        let (child_robj, child_members, child_height, child_midpoint) =
+            build_dendrogram_node(hts, child_label, next_leaf_index)?;
+
+        let pos = offset + child_midpoint;
+        if i == 0 {
+            first_pos = pos;
+        }
+        last_pos = pos;
+        offset += child_members as f64;
+
+        members += child_members;
+        height = height.max(child_height);
+        nodes.push(child_robj);
+    }
+
+    height += 1.0;
+    let midpoint = (first_pos + last_pos) / 2.0;
+
+    let mut robj: Robj = List::from_values(nodes).into();
+    robj.set_attrib("members", members)?;
+    robj.set_attrib("height", height)?;
+    robj.set_attrib("midpoint", midpoint)?;
+    robj.set_class(&["dendrogram"])?;
+
+    Ok((robj, members, height, midpoint))
+}
+
 extendr_module! {
     mod hierarchy;
+    impl AggFn;
     impl HtsSpec;
     impl Hts;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // shared two-state, two-city-per-state toy hierarchy used across the
+    // tree-navigation and construction tests below
+    fn toy_bottom_level() -> Result<Robj, extendr_api::Error> {
+        R!(r#"
+            data.frame(
+                state = c("A", "A", "B", "B"),
+                city = c("A1", "A2", "B1", "B2"),
+                quarter = rep("2024 Q1", 4),
+                value = c(1, 2, 3, 4)
+            )
+        "#)
+    }
+
+    // single-parent, four-child fixture used to check exact AggFn rollup
+    // formulas below; `values` becomes the bottom-level "value" column in the
+    // same order as the "w", "x", "y", "z" leaves
+    fn four_children_bottom_level(values: Vec<f64>) -> Result<Robj, extendr_api::Error> {
+        R!(r#"
+            data.frame(
+                group = rep("P", 4),
+                leaf = c("w", "x", "y", "z"),
+                quarter = rep("2024 Q1", 4),
+                value = {{values}}
+            )
+        "#)
+    }
+
+    // sorted "value" column of an aggregated_series() data.frame, so a single
+    // parent rollup can be checked without assuming which row it lands on
+    fn sorted_values(aggregated: Robj) -> Result<Vec<f64>, extendr_api::Error> {
+        let values: Doubles = R!("{{aggregated}}$value")?.try_into()?;
+        let mut values: Vec<f64> = values.iter().map(|x| x.inner()).collect();
+        values.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        Ok(values)
+    }
+
+    #[test]
+    fn agg_fn_variants_compute_correct_parent_rollups() {
+        test! {
+            // four distinct children under a single parent "P"; bottom-level
+            // values pass through unchanged, so the rollup's own formula can
+            // be checked by hand against the fixed set {1, 2, 3, 4}
+            let children = vec![1.0, 2.0, 3.0, 4.0];
+
+            let mean_fn = AggFn::mean();
+            let mean_spec = HtsSpec::new(
+                vec!["group".into(), "leaf".into()],
+                vec![],
+                Nullable::NotNull(&mean_fn),
+            );
+            let mean_hts = Hts::new(
+                four_children_bottom_level(children.clone())?,
+                &mean_spec,
+                "quarter",
+                "value",
+                false,
+            )?;
+            // arithmetic mean of [1, 2, 3, 4] is 2.5
+            assert_eq!(
+                sorted_values(mean_hts.aggregated_series(false)?)?,
+                vec![1.0, 2.0, 2.5, 3.0, 4.0]
+            );
+
+            let median_fn = AggFn::median();
+            let median_spec = HtsSpec::new(
+                vec!["group".into(), "leaf".into()],
+                vec![],
+                Nullable::NotNull(&median_fn),
+            );
+            let median_hts = Hts::new(
+                four_children_bottom_level(children.clone())?,
+                &median_spec,
+                "quarter",
+                "value",
+                false,
+            )?;
+            // median() is Percentile(0.5): rank = 0.5 * (4 - 1) = 1.5, which
+            // interpolates halfway between the sorted 2nd and 3rd values (2
+            // and 3), landing on 2.5 -- the same as mean() for this
+            // symmetric fixture
+            assert_eq!(
+                sorted_values(median_hts.aggregated_series(false)?)?,
+                vec![1.0, 2.0, 2.5, 3.0, 4.0]
+            );
+
+            let percentile_discrete_fn = AggFn::percentile_discrete(0.9);
+            let percentile_discrete_spec = HtsSpec::new(
+                vec!["group".into(), "leaf".into()],
+                vec![],
+                Nullable::NotNull(&percentile_discrete_fn),
+            );
+            let percentile_discrete_hts = Hts::new(
+                four_children_bottom_level(children.clone())?,
+                &percentile_discrete_spec,
+                "quarter",
+                "value",
+                false,
+            )?;
+            // percentile_discrete(0.9): cumulative count fractions over the
+            // sorted children are 0.25/0.5/0.75/1.0, so the smallest value
+            // whose cumulative fraction is >= 0.9 is the largest child, 4
+            assert_eq!(
+                sorted_values(percentile_discrete_hts.aggregated_series(false)?)?,
+                vec![1.0, 2.0, 3.0, 4.0, 4.0]
+            );
+
+            let mode_fn = AggFn::mode();
+            let mode_spec = HtsSpec::new(
+                vec!["group".into(), "leaf".into()],
+                vec![],
+                Nullable::NotNull(&mode_fn),
+            );
+            let mode_hts = Hts::new(
+                four_children_bottom_level(children)?,
+                &mode_spec,
+                "quarter",
+                "value",
+                false,
+            )?;
+            // every child appears exactly once, so mode() falls back to its
+            // documented lowest-value tie-break: 1
+            assert_eq!(
+                sorted_values(mode_hts.aggregated_series(false)?)?,
+                vec![1.0, 1.0, 2.0, 3.0, 4.0]
+            );
+        }
+    }
+
+    #[test]
+    fn agg_fn_constructors_build_distinct_variants() {
+        test! {
+            let variants = [
+                format!("{:?}", AggFn::sum().inner),
+                format!("{:?}", AggFn::mean().inner),
+                format!("{:?}", AggFn::median().inner),
+                format!("{:?}", AggFn::percentile(0.9).inner),
+                format!("{:?}", AggFn::percentile_discrete(0.9).inner),
+                format!("{:?}", AggFn::mode().inner),
+            ];
+
+            // every constructor must reach hts_core through a different variant
+            for (i, a) in variants.iter().enumerate() {
+                for (j, b) in variants.iter().enumerate() {
+                    assert!(i == j || a != b, "{} and {} collide: {}", i, j, a);
+                }
+            }
+
+            // continuous and discrete percentile must stay distinguishable
+            assert_ne!(
+                format!("{:?}", AggFn::percentile(0.9).inner),
+                format!("{:?}", AggFn::percentile_discrete(0.9).inner),
+            );
+        }
+    }
+
+    #[test]
+    fn as_dendrogram_lists_stay_parallel_and_root_has_no_parent() {
+        test! {
+            let bottom_level: Robj = toy_bottom_level()?;
+
+            let spec = HtsSpec::new(vec!["state".into(), "city".into()], vec![], Nullable::Null);
+            let hts = Hts::new(bottom_level, &spec, "quarter", "value", false)?;
+
+            let dendrogram = hts.inner.dendrogram();
+            assert_eq!(dendrogram.labels.len(), dendrogram.parents.len());
+            assert_eq!(dendrogram.labels.len(), dendrogram.heights.len());
+            assert!(
+                dendrogram.parents.iter().any(|p| p.is_none()),
+                "exactly one node (the root) should have no parent"
+            );
+
+            // the public as_dendrogram() must wrap the same tree as a nested,
+            // base-R-compatible `dendrogram` object
+            let num_roots = dendrogram.parents.iter().filter(|p| p.is_none()).count();
+            assert_eq!(num_roots, 1);
+
+            let root_idx = dendrogram.parents.iter().position(|p| p.is_none()).unwrap();
+            let root_children = hts.children(&dendrogram.labels[root_idx])?;
+
+            let nested = hts.as_dendrogram()?;
+            assert!(nested.class().unwrap().any(|c| c == "dendrogram"));
+            assert_eq!(
+                nested.len(),
+                root_children.len(),
+                "root's dendrogram list must have one entry per direct child"
+            );
+        }
+    }
+
+    #[test]
+    fn as_dendrogram_tags_single_leaf_root_with_dendrogram_class() {
+        test! {
+            // no hierarchy/group columns at all: the single bottom-level
+            // series IS the root, so build_dendrogram_node() takes the leaf
+            // branch for the root itself, which must still carry the
+            // `dendrogram` class, not just the nested-list branch does
+            let bottom_level: Robj = R!(r#"data.frame(quarter = "2024 Q1", value = 1)"#)?;
+            let spec = HtsSpec::new(vec![], vec![], Nullable::Null);
+            let hts = Hts::new(bottom_level, &spec, "quarter", "value", false)?;
+
+            let nested = hts.as_dendrogram()?;
+            assert!(
+                nested.inherits("dendrogram"),
+                "a single-node root must still carry the dendrogram class"
+            );
+        }
+    }
+
+    #[test]
+    fn tree_navigation_methods_cover_happy_and_unknown_node_paths() {
+        test! {
+            let bottom_level: Robj = toy_bottom_level()?;
+
+            let spec = HtsSpec::new(vec!["state".into(), "city".into()], vec![], Nullable::Null);
+            let hts = Hts::new(bottom_level, &spec, "quarter", "value", false)?;
+
+            let tree = hts.inner.dendrogram();
+            let root = tree.labels[tree.parents.iter().position(|p| p.is_none()).unwrap()].clone();
+
+            // children(): root has the two states as direct children
+            let root_children = hts.children(&root)?;
+            assert_eq!(root_children.len(), 2);
+            assert!(root_children.contains(&"A".to_string()));
+            assert!(root_children.contains(&"B".to_string()));
+            assert!(hts.children("no-such-node").is_err());
+
+            // parent(): a bottom-level series points back up to its state
+            assert_eq!(hts.parent("A1")?, Nullable::NotNull("A".to_string()));
+            assert_eq!(hts.parent(&root)?, Nullable::Null);
+            assert!(hts.parent("no-such-node").is_err());
+
+            // leaves_under(): a state's leaves are exactly its own cities
+            let mut leaves = hts.leaves_under("A")?;
+            leaves.sort();
+            assert_eq!(leaves, vec!["A1".to_string(), "A2".to_string()]);
+            assert!(hts.leaves_under("no-such-node").is_err());
+
+            // subtree(): restricting to "A" keeps only its two bottom series
+            let sub = hts.subtree("A")?;
+            assert_eq!(sub.n_bottom(), 2);
+            assert!(hts.subtree("no-such-node").is_err());
+        }
+    }
+
+    #[test]
+    fn lazy_and_eager_construction_agree_on_aggregated_totals() {
+        test! {
+            let spec = HtsSpec::new(vec!["state".into(), "city".into()], vec![], Nullable::Null);
+
+            let eager = Hts::new(toy_bottom_level()?, &spec, "quarter", "value", false)?;
+            let lazy = Hts::new(toy_bottom_level()?, &spec, "quarter", "value", true)?;
+
+            // the lazy path must see the same tree and thread `lazy` through
+            // to `HierarchicalTimeSeries::new` without changing series counts
+            assert_eq!(eager.n_series(), lazy.n_series());
+            assert_eq!(eager.n_bottom(), lazy.n_bottom());
+
+            // the streaming collect on a lazily-built Hts must still reconcile
+            // to the same rolled-up values as the eager, non-streaming path,
+            // not just the same row count -- a regression that drops or
+            // duplicates a group under the streaming collect (wrong join
+            // key, a lost group_by column) would leave row counts unchanged.
+            // `Robj::len()` on a data.frame returns its column count (same as
+            // R's `length(df)`), so row counts must be compared via `nrow()`.
+            let eager_df = eager.aggregated_series(false)?;
+            let lazy_df = lazy.aggregated_series_streaming()?;
+            let eager_rows = R!("nrow({{eager_df}})")?.as_integer().unwrap();
+            let lazy_rows = R!("nrow({{lazy_df}})")?.as_integer().unwrap();
+            assert_eq!(eager_rows, lazy_rows);
+            assert_eq!(sorted_values(eager_df)?, sorted_values(lazy_df)?);
+        }
+    }
+
+    #[test]
+    fn aggregated_series_as_arrow_stream_matches_data_frame_row_count() {
+        test! {
+            let spec = HtsSpec::new(vec!["state".into(), "city".into()], vec![], Nullable::Null);
+            let hts = Hts::new(toy_bottom_level()?, &spec, "quarter", "value", false)?;
+
+            let df = hts.aggregated_series(false)?;
+            let expected_rows = R!("nrow({{df}})")?.as_integer().unwrap();
+
+            // the as_arrow_stream = TRUE branch must be wired all the way
+            // through the public Hts API, not just the lower-level
+            // to_arrow_stream/from_arrow_stream helpers exercised directly
+            // in helpers.rs
+            let stream = hts.aggregated_series(true)?;
+            assert!(
+                is_arrow_stream(&stream),
+                "as_arrow_stream = TRUE must tag its export with the nanoarrow_array_stream class"
+            );
+
+            let reimported = from_arrow_stream(&stream)?;
+            assert_eq!(reimported.height() as i32, expected_rows);
+        }
+    }
+
+    #[test]
+    fn from_parquet_and_from_ipc_surface_missing_file_errors() {
+        test! {
+            let spec = HtsSpec::new(vec!["state".into(), "city".into()], vec![], Nullable::Null);
+
+            // no fixture file exists at this path; the point is to smoke-test
+            // that the path/spec/time_col/value_col/lazy wiring reaches
+            // `hts_core` and surfaces its error instead of panicking
+            let parquet_err = Hts::from_parquet(
+                "does-not-exist.parquet",
+                &spec,
+                "quarter",
+                "value",
+                false,
+            );
+            assert!(parquet_err.is_err());
+
+            let ipc_err = Hts::from_ipc("does-not-exist.arrow", &spec, "quarter", "value", false);
+            assert!(ipc_err.is_err());
+        }
+    }
+
+    #[test]
+    fn from_parquet_and_from_ipc_preserve_native_column_types() {
+        use polars::prelude::*;
+        use std::fs::File;
+
+        test! {
+            // categorical state, boolean group, and a millisecond datetime
+            // period -- the types from_parquet/from_ipc's doc comments claim
+            // to preserve natively, rather than sniffing like from_csv does
+            let state = Series::new("state".into(), &["RJ", "RJ", "SP", "SP"])
+                .cast(&DataType::Categorical(None, CategoricalOrdering::Physical))
+                .map_err(|e| Error::from(e.to_string()))?;
+            let city = Series::new("city".into(), &["Centro", "Zona Norte", "Centro", "Zona Norte"]);
+            let weekday = Series::new("weekday".into(), &[true, false, true, false]);
+            let quarter = Series::new("quarter".into(), &[1_700_000_000_000i64; 4])
+                .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+                .map_err(|e| Error::from(e.to_string()))?;
+            let value = Series::new("value".into(), &[10.0f64, 20.0, 30.0, 40.0]);
+
+            let mut df = DataFrame::new(vec![
+                state.into(),
+                city.into(),
+                weekday.into(),
+                quarter.into(),
+                value.into(),
+            ])
+            .map_err(|e| Error::from(e.to_string()))?;
+
+            let spec = HtsSpec::new(
+                vec!["state".into(), "city".into()],
+                vec!["weekday".into()],
+                Nullable::Null,
+            );
+
+            let parquet_path =
+                std::env::temp_dir().join(format!("rhts_test_{}.parquet", std::process::id()));
+            ParquetWriter::new(File::create(&parquet_path).map_err(|e| Error::from(e.to_string()))?)
+                .finish(&mut df)
+                .map_err(|e| Error::from(e.to_string()))?;
+
+            let parquet_hts =
+                Hts::from_parquet(parquet_path.to_str().unwrap(), &spec, "quarter", "value", false)?;
+            let _ = std::fs::remove_file(&parquet_path);
+            assert_eq!(parquet_hts.n_bottom(), 4);
+            let mut rj_children = parquet_hts.children("RJ")?;
+            rj_children.sort();
+            assert_eq!(rj_children, vec!["Centro".to_string(), "Zona Norte".to_string()]);
+            let parquet_out = parquet_hts.aggregated_series(false)?;
+            let parquet_is_datetime: bool =
+                R!(r#"inherits({{parquet_out}}$quarter, "POSIXct")"#)?.as_bool().unwrap();
+            assert!(
+                parquet_is_datetime,
+                "from_parquet must load quarter as a native datetime, not a sniffed string"
+            );
+
+            let ipc_path = std::env::temp_dir().join(format!("rhts_test_{}.arrow", std::process::id()));
+            IpcWriter::new(File::create(&ipc_path).map_err(|e| Error::from(e.to_string()))?)
+                .finish(&mut df)
+                .map_err(|e| Error::from(e.to_string()))?;
+
+            let ipc_hts = Hts::from_ipc(ipc_path.to_str().unwrap(), &spec, "quarter", "value", false)?;
+            let _ = std::fs::remove_file(&ipc_path);
+            assert_eq!(ipc_hts.n_bottom(), 4);
+            let ipc_out = ipc_hts.aggregated_series(false)?;
+            let ipc_is_datetime: bool =
+                R!(r#"inherits({{ipc_out}}$quarter, "POSIXct")"#)?.as_bool().unwrap();
+            assert!(
+                ipc_is_datetime,
+                "from_ipc must load quarter as a native datetime, not a sniffed string"
+            );
+        }
+    }
+
+    #[test]
+    fn from_parquet_handles_float32_value_and_int64_group_without_panicking() {
+        use polars::prelude::*;
+        use std::fs::File;
+
+        test! {
+            // Float32 value column and an Int64 group column are native
+            // widths commonly found in Parquet files but never produced by
+            // from_robj (which only ever emits f64/i32 columns); to_robj
+            // used to panic via f64()/i32() downcasting the moment
+            // aggregated_series() was called on either
+            let state = Series::new("state".into(), &["RJ", "RJ", "SP", "SP"]);
+            let city = Series::new("city".into(), &["Centro", "Zona Norte", "Centro", "Zona Norte"]);
+            let region_code = Series::new("region_code".into(), &[10i64, 10, 20, 20]);
+            let quarter = Series::new("quarter".into(), &["2024Q1", "2024Q1", "2024Q1", "2024Q1"]);
+            let value = Series::new("value".into(), &[10.0f32, 20.0, 30.0, 40.0]);
+
+            let mut df = DataFrame::new(vec![
+                state.into(),
+                city.into(),
+                region_code.into(),
+                quarter.into(),
+                value.into(),
+            ])
+            .map_err(|e| Error::from(e.to_string()))?;
+
+            let spec = HtsSpec::new(
+                vec!["state".into(), "city".into()],
+                vec!["region_code".into()],
+                Nullable::Null,
+            );
+
+            let parquet_path =
+                std::env::temp_dir().join(format!("rhts_test_wide_{}.parquet", std::process::id()));
+            ParquetWriter::new(File::create(&parquet_path).map_err(|e| Error::from(e.to_string()))?)
+                .finish(&mut df)
+                .map_err(|e| Error::from(e.to_string()))?;
+
+            let hts =
+                Hts::from_parquet(parquet_path.to_str().unwrap(), &spec, "quarter", "value", false)?;
+            let _ = std::fs::remove_file(&parquet_path);
+
+            let out = hts.aggregated_series(false)?;
+            let nrow: i32 = R!("nrow({{out}})")?.as_integer().unwrap();
+            assert!(nrow > 0);
+            let numeric: bool = R!(r#"
+                is.numeric({{out}}$value) && is.numeric({{out}}$region_code)
+            "#)?
+            .as_bool()
+            .unwrap();
+            assert!(numeric);
+        }
+    }
+}